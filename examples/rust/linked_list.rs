@@ -1,9 +1,13 @@
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+#[derive(Debug)]
 enum List<T> {
     Cons(T, Box<List<T>>),
     Nil,
 }
 
-impl<T: std::fmt::Display> List<T> {
+impl<T> List<T> {
     fn new() -> Self {
         List::Nil
     }
@@ -13,27 +17,250 @@ impl<T: std::fmt::Display> List<T> {
     }
 
     fn len(&self) -> usize {
-        match self {
-            List::Cons(_, tail) => 1 + tail.len(),
-            List::Nil => 0,
+        self.iter().count()
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            cursor: Some(self),
         }
     }
 
-    fn to_string(&self) -> String {
+    /// Detaches the head of the list in place, returning it along with
+    /// ownership of the rest of the chain now held in `self`.
+    ///
+    /// The borrow checker forbids moving `head`/`tail` out of a `List<T>` by
+    /// matching on it by value, since `List<T>` implements `Drop`. Reading
+    /// them out through raw pointers and forgetting the now-empty shell
+    /// sidesteps that restriction; it is also what keeps this, `IntoIter`,
+    /// and our `Drop` impl iterative instead of recursive.
+    fn pop_front(&mut self) -> Option<T> {
         match self {
+            List::Nil => None,
+            List::Cons(..) => {
+                let mut old = std::mem::ManuallyDrop::new(std::mem::replace(self, List::Nil));
+                let List::Cons(head, tail) = &mut *old else {
+                    unreachable!()
+                };
+                // SAFETY: `old` is `ManuallyDrop`, so these fields are never
+                // dropped through `old` itself; we take ownership of each
+                // exactly once here and reinstate `tail` into `self` below.
+                let head = unsafe { std::ptr::read(head) };
+                let tail = unsafe { std::ptr::read(tail) };
+                *self = *tail;
+                Some(head)
+            }
+        }
+    }
+
+    /// Reverses the list, moving each node iteratively into a new chain.
+    fn reverse(self) -> Self {
+        let mut result = List::Nil;
+        for item in self {
+            result = result.prepend(item);
+        }
+        result
+    }
+
+    /// Returns a new list containing `self`'s elements followed by `other`'s.
+    fn append(self, other: Self) -> Self {
+        let mut result = other;
+        for item in self.reverse() {
+            result = result.prepend(item);
+        }
+        result
+    }
+
+    /// Builds a new list by applying `f` to each element, preserving order.
+    fn map<U, F: Fn(&T) -> U>(&self, f: F) -> List<U> {
+        self.iter().map(f).collect()
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of bounds.
+    fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for List<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut iter = self.iter();
+        if let Some(head) = iter.next() {
+            write!(f, "{}", head)?;
+        }
+        for item in iter {
+            write!(f, " -> {}", item)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+/// A borrowing iterator over the elements of a [`List`].
+struct Iter<'a, T> {
+    cursor: Option<&'a List<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.cursor.take()? {
             List::Cons(head, tail) => {
-                let rest = tail.to_string();
-                if rest.is_empty() {
-                    format!("{}", head)
-                } else {
-                    format!("{} -> {}", head, rest)
-                }
+                self.cursor = Some(tail);
+                Some(head)
+            }
+            List::Nil => None,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An owning iterator over the elements of a [`List`].
+struct IntoIter<T>(List<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut items: Vec<T> = iter.into_iter().collect();
+        let mut list = List::Nil;
+        while let Some(item) = items.pop() {
+            list = List::Cons(item, Box::new(list));
+        }
+        list
+    }
+}
+
+/// A node in a [`SharedList`]'s chain.
+#[derive(Debug)]
+enum SharedNode<T> {
+    Cons(T, Rc<SharedNode<T>>),
+    Nil,
+}
+
+/// A persistent, immutable singly-linked list with structural sharing.
+///
+/// Unlike [`List`], [`prepend`](SharedList::prepend) takes `&self` and
+/// returns a new list that shares the existing tail via a cloned `Rc`,
+/// leaving the original list intact. Cloning a `SharedList` is O(1): it
+/// only bumps a reference count.
+#[derive(Debug)]
+struct SharedList<T>(Rc<SharedNode<T>>);
+
+impl<T> SharedList<T> {
+    fn new() -> Self {
+        SharedList(Rc::new(SharedNode::Nil))
+    }
+
+    fn prepend(&self, value: T) -> Self {
+        SharedList(Rc::new(SharedNode::Cons(value, Rc::clone(&self.0))))
+    }
+
+    fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    fn iter(&self) -> SharedIter<'_, T> {
+        SharedIter {
+            cursor: Some(&self.0),
+        }
+    }
+}
+
+impl<T> Clone for SharedList<T> {
+    fn clone(&self) -> Self {
+        SharedList(Rc::clone(&self.0))
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for SharedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut iter = self.iter();
+        if let Some(head) = iter.next() {
+            write!(f, "{}", head)?;
+        }
+        for item in iter {
+            write!(f, " -> {}", item)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Drop for SharedList<T> {
+    fn drop(&mut self) {
+        // `self.0`'s `Rc::drop` already deallocates this node once nothing
+        // else references it. What we guard against here is the *default*
+        // recursive drop that would otherwise follow through a long,
+        // uniquely-owned tail: walk it ourselves, one node at a time, and
+        // stop the instant a node is still shared elsewhere (`try_unwrap`
+        // fails), leaving it for its other owners to eventually drop.
+        let mut cur = std::mem::replace(&mut self.0, Rc::new(SharedNode::Nil));
+        while let Ok(node) = Rc::try_unwrap(cur) {
+            match node {
+                SharedNode::Cons(_, next) => cur = next,
+                SharedNode::Nil => break,
+            }
+        }
+    }
+}
+
+/// A borrowing iterator over the elements of a [`SharedList`].
+struct SharedIter<'a, T> {
+    cursor: Option<&'a SharedNode<T>>,
+}
+
+impl<'a, T> Iterator for SharedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.cursor.take()? {
+            SharedNode::Cons(head, tail) => {
+                self.cursor = Some(tail);
+                Some(head)
             }
-            List::Nil => String::new(),
+            SharedNode::Nil => None,
         }
     }
 }
 
+impl<'a, T> IntoIterator for &'a SharedList<T> {
+    type Item = &'a T;
+    type IntoIter = SharedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 fn main() {
     let list = List::new()
         .prepend(3)
@@ -41,5 +268,42 @@ fn main() {
         .prepend(1);
 
     println!("Length: {}", list.len());
-    println!("List: {}", list.to_string());
+    println!("List: {}", list);
+
+    let sum: i32 = list.iter().sum();
+    println!("Sum: {}", sum);
+
+    let collected: List<i32> = (1..=3).collect();
+    println!("Collected: {}", collected);
+
+    let doubled = collected.map(|x| x * 2);
+    println!("Doubled: {}", doubled);
+    println!("Get(1): {:?}", doubled.get(1));
+
+    let reversed = doubled.reverse();
+    println!("Reversed: {}", reversed);
+
+    let appended = reversed.append(List::new().prepend(20).prepend(10));
+    println!("Appended: {}", appended);
+
+    // A list with a million nodes would overflow the stack with a recursive
+    // len/Drop; this confirms the iterative versions hold up.
+    let long_list: List<i32> = (0..1_000_000).collect();
+    assert_eq!(long_list.len(), 1_000_000);
+    println!("Long list length: {}", long_list.len());
+    drop(long_list);
+
+    let shared = SharedList::new().prepend(3).prepend(2).prepend(1);
+    let shared_branch = shared.prepend(0);
+
+    println!("Shared: {}", shared);
+    println!("Shared branch: {}", shared_branch);
+    println!("Shared length: {}", shared.len());
+
+    let mut long_shared = SharedList::new();
+    for i in 0..1_000_000 {
+        long_shared = long_shared.prepend(i);
+    }
+    println!("Long shared length: {}", long_shared.len());
+    drop(long_shared);
 }